@@ -36,11 +36,25 @@ Provides the [`Boolinator`](trait.Boolinator.html) trait, which lets you use `Op
 
 ## Compatibility
 
-v0.1.0 was tested against `rustc` versions 1.0.0, 1.1.0, 1.2.0, 1.3.0, 1.4.0, 1.5.0, 1.6.0, 1.7.0-beta.1, and nightly 2016-01-20.
+v0.1.0 was tested against `rustc` versions 1.0.0, 1.1.0, 1.2.0, 1.3.0, 1.4.0, 1.5.0, 1.6.0, 1.7.0-beta.1, and nightly 2016-01-20. That remains the baseline MSRV for the crate as built by default.
+
+The [`const_fn`](const_fn/index.html) module is opt-in behind the `const_fn`
+Cargo feature (disabled by default) and requires a `rustc` new enough to
+support `if`/`else`, `panic!` and generic trait bounds inside `const fn`
+(1.46, 1.57 and 1.61 respectively, so effectively 1.61). Enabling the feature on an older
+toolchain will fail to build; leaving it disabled keeps the 1.0.0 baseline
+above intact.
 
 */
 // Can't have undocumented APIs!  Nosiree!
 #![deny(missing_docs)]
+// The `as_*` combinators intentionally take `self` by value (`bool` is `Copy`,
+// so there's no reference to take), and `expect`'s `if self { () } else { .. }`
+// makes its two-armed symmetry with the other combinators obvious at a glance.
+#![allow(clippy::wrong_self_convention, clippy::unused_unit, clippy::redundant_static_lifetimes)]
+// `ok_or`/`ok_or_else` deliberately mirror `Option::ok_or`'s name for familiar
+// ergonomics, at the cost of this lint should `bool` ever grow its own.
+#![allow(unstable_name_collisions)]
 
 /**
 This trait defines a number of combinator-style methods for use with `bool` values.
@@ -90,6 +104,37 @@ pub trait Boolinator: Sized {
     Panics with `msg` if this value is `false`, otherwise it does nothing.
     */
     fn expect(self, msg: &str);
+
+    /**
+    If this value is `true`, returns `Ok(())`; `Err(err)` otherwise.
+    */
+    fn ok_or<E>(self, err: E) -> Result<(), E>;
+
+    /**
+    If this value is `true`, returns `Ok(())`; `Err(err())` otherwise.
+    */
+    fn ok_or_else<E, G>(self, err: G) -> Result<(), E>
+    where G: FnOnce() -> E;
+
+    /**
+    Panics with `msg` if this value is `false`, otherwise returns `self`
+    unchanged, allowing further combinators to be chained, e.g.
+    `cond.expect_true("must hold").as_some(x)`.
+    */
+    fn expect_true(self, msg: &str) -> Self;
+
+    /**
+    Panics with `msg` if this value is `true`, otherwise returns `self`
+    unchanged. The negative-assertion counterpart to `expect_true`.
+    */
+    fn expect_false(self, msg: &str) -> Self;
+
+    /**
+    Returns `truthy` if this value is `true`, `falsy` otherwise. The inverse
+    of [`from_truthy`](fn.from_truthy.html) for the common case of a single
+    pair of tokens.
+    */
+    fn to_token(self, truthy: &'static str, falsy: &'static str) -> &'static str;
 }
 
 impl Boolinator for bool {
@@ -129,6 +174,251 @@ impl Boolinator for bool {
     fn expect(self, msg: &str) {
         if self { () } else { panic!("{}", msg) }
     }
+
+    fn ok_or<E>(self, err: E) -> Result<(), E> {
+        if self { Ok(()) } else { Err(err) }
+    }
+
+    fn ok_or_else<E, G>(self, err: G) -> Result<(), E>
+    where G: FnOnce() -> E {
+        if self { Ok(()) } else { Err(err()) }
+    }
+
+    #[inline]
+    fn expect_true(self, msg: &str) -> Self {
+        if self { self } else { panic!("{}", msg) }
+    }
+
+    #[inline]
+    fn expect_false(self, msg: &str) -> Self {
+        if self { panic!("{}", msg) } else { self }
+    }
+
+    #[inline]
+    fn to_token(self, truthy: &'static str, falsy: &'static str) -> &'static str {
+        if self { truthy } else { falsy }
+    }
+}
+
+/**
+Parses a textual boolean representation, recognising a small, fixed set of
+truthy/falsy tokens (`"true"`/`"1"`/`"yes"`/`"on"` vs. `"false"`/`"0"`/`"no"`/
+`"off"`), case-insensitively. Returns `None` for anything else, so callers
+can chain it with `Option`'s own combinators, e.g.
+`from_truthy(s).ok_or("invalid flag")?`.
+*/
+pub fn from_truthy(s: &str) -> Option<bool> {
+    match s.to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Some(true),
+        "false" | "0" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/**
+`const fn` counterparts to the payload-free and by-value `Boolinator` combinators.
+
+Rust does not currently allow trait methods to be declared `const fn` on
+stable (see [rust-lang/rust#67792](https://github.com/rust-lang/rust/issues/67792)),
+so the combinators that only ever perform a trivial `if self { .. } else { .. }`
+branch are duplicated here as free functions taking the `bool` as a plain
+argument. This lets them be used inside `const`/`static` initializers, e.g.
+
+```
+use boolinator::const_fn;
+const MODE: Option<u8> = const_fn::as_some(true, 3);
+```
+
+The closure-taking `*_from` variants are not provided here, since closures
+cannot be invoked in `const fn` on stable Rust. The payload-carrying
+functions additionally require `T`/`E: Copy`, since stable Rust cannot
+yet prove at compile time that the unused branch's value never needs
+dropping otherwise.
+
+This module is gated behind the `const_fn` Cargo feature (disabled by
+default), since it bumps the effective MSRV to 1.61 — enable it with
+`boolinator = { version = "...", features = ["const_fn"] }` if your
+toolchain supports it.
+*/
+#[cfg(feature = "const_fn")]
+pub mod const_fn {
+    /**
+    `const fn` version of [`Boolinator::as_option`](../trait.Boolinator.html#tymethod.as_option).
+    */
+    pub const fn as_option(b: bool) -> Option<()> {
+        if b { Some(()) } else { None }
+    }
+
+    /**
+    `const fn` version of [`Boolinator::as_some`](../trait.Boolinator.html#tymethod.as_some).
+    */
+    pub const fn as_some<T: Copy>(b: bool, some: T) -> Option<T> {
+        if b { Some(some) } else { None }
+    }
+
+    /**
+    `const fn` version of [`Boolinator::and_option`](../trait.Boolinator.html#tymethod.and_option).
+    */
+    pub const fn and_option<T: Copy>(b: bool, opt: Option<T>) -> Option<T> {
+        if b { opt } else { None }
+    }
+
+    /**
+    `const fn` version of [`Boolinator::as_result`](../trait.Boolinator.html#tymethod.as_result).
+    */
+    pub const fn as_result<T: Copy, E: Copy>(b: bool, ok: T, err: E) -> Result<T, E> {
+        if b { Ok(ok) } else { Err(err) }
+    }
+
+    /**
+    `const fn` version of [`Boolinator::ok_or`](../trait.Boolinator.html#tymethod.ok_or).
+    */
+    pub const fn ok_or<E: Copy>(b: bool, err: E) -> Result<(), E> {
+        if b { Ok(()) } else { Err(err) }
+    }
+
+    /**
+    `const fn` version of [`Boolinator::expect`](../trait.Boolinator.html#tymethod.expect).
+    */
+    pub const fn expect(b: bool, msg: &str) {
+        if !b { panic!("{}", msg) }
+    }
+}
+
+/**
+This trait defines combinator-style methods for Kleene's three-valued logic
+over `Option<bool>`, where `Some(true)`, `Some(false)` and `None` represent
+true, false and unknown respectively.
+*/
+pub trait TriBoolinator: Sized {
+    /**
+    Three-valued logical AND. `Some(false)` dominates (it is returned if
+    either side is `Some(false)`, even if the other side is `None`);
+    otherwise `None` dominates; otherwise both sides are `Some(true)`.
+    */
+    fn and3(self, other: Self) -> Self;
+
+    /**
+    Three-valued logical OR; the dual of `and3`. `Some(true)` dominates,
+    then `None`, then `Some(false)`.
+    */
+    fn or3(self, other: Self) -> Self;
+
+    /**
+    Three-valued logical NOT. `Some(b)` becomes `Some(!b)`; `None` (unknown)
+    stays `None`.
+    */
+    fn not3(self) -> Self;
+
+    /**
+    If this value is definitely `Some(true)`, returns `Some(some)`;
+    otherwise (`Some(false)`, or undecided `None`) returns `None`.
+    */
+    fn as_some<T>(self, some: T) -> Option<T>;
+
+    /**
+    If this value is definitely `Some(true)`, returns `Ok(ok)`; otherwise
+    (`Some(false)`, or undecided `None`) returns `Err(err)`.
+    */
+    fn as_result<T, E>(self, ok: T, err: E) -> Result<T, E>;
+}
+
+impl TriBoolinator for Option<bool> {
+    fn and3(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        }
+    }
+
+    fn or3(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        }
+    }
+
+    fn not3(self) -> Self {
+        self.map(|b| !b)
+    }
+
+    fn as_some<T>(self, some: T) -> Option<T> {
+        match self {
+            Some(true) => Some(some),
+            _ => None,
+        }
+    }
+
+    fn as_result<T, E>(self, ok: T, err: E) -> Result<T, E> {
+        match self {
+            Some(true) => Ok(ok),
+            _ => Err(err),
+        }
+    }
+}
+
+/**
+A builder that runs a batch of `bool` checks and collects every failure,
+rather than short-circuiting on the first one like `Boolinator::as_result`
+does. Useful for form- or config-style validation, where callers want every
+problem reported at once.
+
+```
+use boolinator::Checks;
+
+let result = Checks::new()
+    .check(1 + 1 == 2, "math is broken")
+    .check(1 + 1 == 3, "math is still broken")
+    .finish();
+
+assert_eq!(result, Err(vec!["math is still broken"]));
+```
+*/
+pub struct Checks<E> {
+    errors: Vec<E>,
+}
+
+impl<E> Checks<E> {
+    /**
+    Creates an empty batch of checks.
+    */
+    pub fn new() -> Self {
+        Checks { errors: Vec::new() }
+    }
+
+    /**
+    Records `err` if `cond` is `false`; otherwise does nothing.
+    */
+    pub fn check(mut self, cond: bool, err: E) -> Self {
+        if !cond { self.errors.push(err); }
+        self
+    }
+
+    /**
+    Records `err()` if `cond` is `false`, without evaluating `err` when
+    `cond` is `true`.
+    */
+    pub fn check_with<F>(mut self, cond: bool, err: F) -> Self
+    where F: FnOnce() -> E {
+        if !cond { self.errors.push(err()); }
+        self
+    }
+
+    /**
+    Finishes the batch: `Ok(())` if every check held, otherwise `Err` with
+    the error of every failed check, in the order they were checked.
+    */
+    pub fn finish(self) -> Result<(), Vec<E>> {
+        if self.errors.is_empty() { Ok(()) } else { Err(self.errors) }
+    }
+}
+
+impl<E> Default for Checks<E> {
+    fn default() -> Self {
+        Checks::new()
+    }
 }
 
 /*
@@ -137,6 +427,9 @@ Serious code must have serious tests, and Boolinator is serious business!
 #[cfg(test)]
 mod tests {
     use super::Boolinator; // as opposed to the original NES version.
+    use super::TriBoolinator;
+    use super::Checks;
+    use super::from_truthy;
 
     #[test]
     fn test_as_option() {
@@ -197,6 +490,170 @@ mod tests {
         assert_eq!(false.as_result_from(|| "four space indent", || "anything else"), Err("anything else"));
     }
 
+    #[test]
+    fn test_ok_or() {
+        // No payload, no problem.
+        assert_eq!(true.ok_or("nope"), Ok(()));
+        assert_eq!(false.ok_or("nope"), Err("nope"));
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_lazy_evaluations)] // the point of this test is the lazy variant
+    fn test_ok_or_else() {
+        // Lazy as it should be.
+        assert_eq!(true.ok_or_else(|| "nope"), Ok(()));
+        assert_eq!(false.ok_or_else(|| "nope"), Err("nope"));
+    }
+
+    #[test]
+    #[cfg(feature = "const_fn")]
+    fn test_const_fn() {
+        use super::const_fn;
+
+        // If it typechecks as a const, it works at runtime too.
+        const OPT: Option<()> = const_fn::as_option(true);
+        const SOME: Option<u8> = const_fn::as_some(true, 3);
+        const AND: Option<u8> = const_fn::and_option(true, Some(5));
+        const RESULT: Result<u8, &str> = const_fn::as_result(false, 1, "nope");
+        const OK_OR: Result<(), &str> = const_fn::ok_or(false, "nope");
+
+        assert_eq!(OPT, Some(()));
+        assert_eq!(SOME, Some(3));
+        assert_eq!(AND, Some(5));
+        assert_eq!(RESULT, Err("nope"));
+        assert_eq!(OK_OR, Err("nope"));
+
+        const_fn::expect(true, "always holds");
+    }
+
+    #[test]
+    #[cfg(feature = "const_fn")]
+    #[should_panic]
+    fn test_const_fn_expect_panics() {
+        super::const_fn::expect(false, "never holds");
+    }
+
+    #[test]
+    fn test_and3() {
+        // Unknown is contagious, unless false already won.
+        assert_eq!(Some(true).and3(Some(true)), Some(true));
+        assert_eq!(Some(true).and3(Some(false)), Some(false));
+        assert_eq!(Some(false).and3(None), Some(false));
+        assert_eq!(None.and3(Some(false)), Some(false));
+        assert_eq!(Some(true).and3(None), None);
+        assert_eq!(None.and3(None), None);
+    }
+
+    #[test]
+    fn test_or3() {
+        // The dual of the above, obviously.
+        assert_eq!(Some(false).or3(Some(false)), Some(false));
+        assert_eq!(Some(false).or3(Some(true)), Some(true));
+        assert_eq!(Some(true).or3(None), Some(true));
+        assert_eq!(None.or3(Some(true)), Some(true));
+        assert_eq!(Some(false).or3(None), None);
+        assert_eq!(None.or3(None), None);
+    }
+
+    #[test]
+    fn test_not3() {
+        // Flip it and reverse it.
+        assert_eq!(Some(true).not3(), Some(false));
+        assert_eq!(Some(false).not3(), Some(true));
+        assert_eq!(None.not3(), None);
+    }
+
+    #[test]
+    fn test_tri_as_some() {
+        // Only a definite yes will do.
+        assert_eq!(Some(true).as_some("yep"), Some("yep"));
+        assert_eq!(Some(false).as_some("yep"), None);
+        assert_eq!(None.as_some("yep"), None);
+    }
+
+    #[test]
+    fn test_tri_as_result() {
+        // Undecided is still not okay.
+        assert_eq!(Some(true).as_result("yep", "nope"), Ok("yep"));
+        assert_eq!(Some(false).as_result("yep", "nope"), Err("nope"));
+        assert_eq!(None.as_result("yep", "nope"), Err("nope"));
+    }
+
+    #[test]
+    fn test_checks_all_pass() {
+        // Every box ticked.
+        let result = Checks::new()
+            .check(true, "unreachable")
+            .check(1 + 1 == 2, "also unreachable")
+            .finish();
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_checks_collects_all_failures() {
+        // No short-circuiting allowed.
+        let result = Checks::new()
+            .check(false, "first")
+            .check(true, "skipped")
+            .check(false, "second")
+            .finish();
+        assert_eq!(result, Err(vec!["first", "second"]));
+    }
+
+    #[test]
+    fn test_checks_with() {
+        // Lazy errors for expensive messages.
+        let result = Checks::new()
+            .check_with(false, || "lazy")
+            .finish();
+        assert_eq!(result, Err(vec!["lazy"]));
+    }
+
+    #[test]
+    fn test_expect_true() {
+        // Chainable, unlike its plain cousin.
+        assert_eq!(true.expect_true("must hold").as_some("body"), Some("body"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_expect_true_panics() {
+        false.expect_true("must hold");
+    }
+
+    #[test]
+    fn test_expect_false() {
+        // The mirror image.
+        assert_eq!(false.expect_false("must not hold").as_some("body"), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_expect_false_panics() {
+        true.expect_false("must not hold");
+    }
+
+    #[test]
+    fn test_to_token() {
+        // Stringly typed, combinator styled.
+        assert_eq!(true.to_token("yes", "no"), "yes");
+        assert_eq!(false.to_token("yes", "no"), "no");
+    }
+
+    #[test]
+    fn test_from_truthy() {
+        // Loud and quiet, upper and lower.
+        assert_eq!(from_truthy("true"), Some(true));
+        assert_eq!(from_truthy("YES"), Some(true));
+        assert_eq!(from_truthy("On"), Some(true));
+        assert_eq!(from_truthy("1"), Some(true));
+        assert_eq!(from_truthy("false"), Some(false));
+        assert_eq!(from_truthy("NO"), Some(false));
+        assert_eq!(from_truthy("Off"), Some(false));
+        assert_eq!(from_truthy("0"), Some(false));
+        assert_eq!(from_truthy("maybe"), None);
+    }
+
     const DREAMS: &'static str = "love and financial security";
 
     #[test]